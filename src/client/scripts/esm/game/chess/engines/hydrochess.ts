@@ -77,7 +77,7 @@ self.onmessage = async function (e: MessageEvent<EngineWorkerMessage>): Promise<
 		const initialized = await initWasm();
 		if (!initialized) {
 			console.error('[Engine] WASM module failed to initialize');
-			postMessage({ type: 'move', data: null });
+			postFailure(data);
 			return;
 		}
 	}
@@ -109,24 +109,28 @@ self.onmessage = async function (e: MessageEvent<EngineWorkerMessage>): Promise<
 			engine = wasm.Engine.from_icn(icnString, engineConfig);
 		} catch (e) {
 			console.error('[Engine] Failed to start engine from ICN:', e);
-			postMessage({ type: 'move', data: null });
+			postFailure(data);
 			return;
 		}
 
-		// Send generated moves for debugging if requested
-		if (data.requestGeneratedMoves === true) {
-			const legalMoves: WasmBestMoveResult[] = engine.get_legal_moves_js();
-			const formattedMoves: string[] = legalMoves.map((m) => `${m.from}>${m.to}`);
-			// Send the generated moves back to the main thread for rendering
-			postMessage({ type: 'generatedMoves', data: formattedMoves });
+		let bestMoveResult: WasmBestMoveResult | undefined;
+		try {
+			// Send generated moves for debugging if requested
+			if (data.requestGeneratedMoves === true) {
+				const legalMoves: WasmBestMoveResult[] = engine.get_legal_moves_js();
+				const formattedMoves: string[] = legalMoves.map((m) => `${m.from}>${m.to}`);
+				// Send the generated moves back to the main thread for rendering
+				postMessage({ type: 'generatedMoves', data: formattedMoves });
+				return;
+			}
+
+			const timeLimit = data.engineConfig?.engineTimeLimitPerMoveMillis ?? 0;
+			bestMoveResult = engine.get_best_move_with_time(timeLimit, true);
+		} finally {
+			// Release the WASM engine even if a call into it threw, so its memory isn't leaked.
 			engine.free();
-			return;
 		}
 
-		const timeLimit = data.engineConfig?.engineTimeLimitPerMoveMillis ?? 0;
-		const bestMoveResult = engine.get_best_move_with_time(timeLimit, true);
-		engine.free();
-
 		if (!bestMoveResult) {
 			console.error('[Engine] No best move result returned from WASM');
 			postMessage({ type: 'move', data: null });
@@ -145,10 +149,19 @@ self.onmessage = async function (e: MessageEvent<EngineWorkerMessage>): Promise<
 		postMessage({ type: 'move', data: moveString });
 	} catch (error) {
 		console.error(`[Engine] Error finding best move:`, error);
-		postMessage({ type: 'move', data: null });
+		postFailure(data);
 	}
 };
 
+/**
+ * Reports to the main thread that the request could not be fulfilled.
+ * Generated move requests receive an empty list, so a failed debug request never resigns the game.
+ */
+function postFailure(data: EngineWorkerMessage): void {
+	if (data.requestGeneratedMoves === true) postMessage({ type: 'generatedMoves', data: [] });
+	else postMessage({ type: 'move', data: null });
+}
+
 function mapRustPromotionToSiteAbbr(
 	promotion: string | null | undefined,
 	engineColor: number,